@@ -0,0 +1,142 @@
+use std::cell::Cell;
+
+use crate::{Address32, Hasher, Key, U256};
+
+/// Sub-indices of the header fields, within tree-index 0.
+pub(crate) const VERSION_LEAF_KEY: u8 = 0;
+pub(crate) const BALANCE_LEAF_KEY: u8 = 1;
+pub(crate) const NONCE_LEAF_KEY: u8 = 2;
+pub(crate) const CODE_KECCAK_LEAF_KEY: u8 = 3;
+pub(crate) const CODE_SIZE_LEAF_KEY: u8 = 4;
+
+/// First sub-index, within tree-index 0, that code chunks (rather than
+/// header fields) occupy.
+pub(crate) const CODE_OFFSET: u64 = 128;
+/// Number of sub-indices (and hence code chunks) sharing a single stem.
+pub(crate) const VERKLE_NODE_WIDTH: u64 = 256;
+
+/// Caches the 31-byte stem shared by every tree key of a given
+/// `(address, tree_index)`, recomputing it only when `tree_index` changes.
+///
+/// `tree_index` is a full `U256`, not a small counter: storage slots from a
+/// `keccak256` mapping/array layout land on tree-indices spread uniformly
+/// over the whole 256-bit range, so this cannot be narrowed to a machine
+/// word without losing real storage slots.
+///
+/// All of an account's header fields share tree-index 0, and so do the
+/// first 128 code chunks; further chunks roll over into tree-index 1, 2,
+/// and so on. `AccountKeys` lets callers walk many keys for the same
+/// account while paying for the underlying commitment once per run of keys
+/// with the same tree-index, instead of once per field or chunk. Only the
+/// most recently used tree-index is cached, so going back to an earlier
+/// tree-index recomputes its stem. The cache is kept behind
+/// a [`Cell`] so that callers can keep computing keys through a shared
+/// reference, just as they could before this type existed.
+pub struct AccountKeys {
+    address: Address32,
+    stem_of: fn(Address32, U256) -> [u8; 31],
+    cache: Cell<(U256, [u8; 31])>,
+}
+
+impl AccountKeys {
+    pub fn new<H: Hasher>(address: Address32) -> Self {
+        AccountKeys {
+            address,
+            stem_of: H::stem,
+            cache: Cell::new((U256::zero(), H::stem(address, U256::zero()))),
+        }
+    }
+
+    /// Returns the key for `(tree_index, sub_index)`, recomputing the
+    /// cached stem only if `tree_index` differs from the last one used.
+    pub fn key(&self, tree_index: U256, sub_index: u8) -> Key {
+        let (cached_tree_index, cached_stem) = self.cache.get();
+        let stem = if tree_index == cached_tree_index {
+            cached_stem
+        } else {
+            let stem = (self.stem_of)(self.address, tree_index);
+            self.cache.set((tree_index, stem));
+            stem
+        };
+        Key::from_stem(stem, sub_index)
+    }
+
+    pub fn version(&self) -> Key {
+        self.key(U256::zero(), VERSION_LEAF_KEY)
+    }
+
+    pub fn balance(&self) -> Key {
+        self.key(U256::zero(), BALANCE_LEAF_KEY)
+    }
+
+    pub fn nonce(&self) -> Key {
+        self.key(U256::zero(), NONCE_LEAF_KEY)
+    }
+
+    pub fn code_keccak(&self) -> Key {
+        self.key(U256::zero(), CODE_KECCAK_LEAF_KEY)
+    }
+
+    pub fn code_size(&self) -> Key {
+        self.key(U256::zero(), CODE_SIZE_LEAF_KEY)
+    }
+
+    /// Derives the key of a single code chunk.
+    pub fn code_chunk(&self, chunk_id: u64) -> Key {
+        let position = CODE_OFFSET + chunk_id;
+        self.key(
+            U256::from(position / VERKLE_NODE_WIDTH),
+            (position % VERKLE_NODE_WIDTH) as u8,
+        )
+    }
+
+    /// Yields `(chunk_id, Key)` for `chunk_id` in `start_chunk_id..start_chunk_id + count`,
+    /// re-deriving the stem only on the (rare) tree-index rollover rather
+    /// than once per chunk.
+    pub fn code_chunk_keys(
+        &self,
+        start_chunk_id: u64,
+        count: u64,
+    ) -> impl Iterator<Item = (u64, Key)> + '_ {
+        (start_chunk_id..start_chunk_id + count).map(|chunk_id| (chunk_id, self.code_chunk(chunk_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestHasher;
+    impl Hasher for TestHasher {}
+
+    #[test]
+    fn header_fields_share_a_stem() {
+        let keys = AccountKeys::new::<TestHasher>([7u8; 32]);
+        let stem = TestHasher::stem([7u8; 32], U256::zero());
+        assert_eq!(keys.version(), Key::from_stem(stem, VERSION_LEAF_KEY));
+        assert_eq!(keys.balance(), Key::from_stem(stem, BALANCE_LEAF_KEY));
+    }
+
+    #[test]
+    fn code_chunks_roll_over_to_the_next_tree_index() {
+        let keys = AccountKeys::new::<TestHasher>([7u8; 32]);
+        let first_chunk_in_tree_index_1 = VERKLE_NODE_WIDTH - CODE_OFFSET;
+        let key = keys.code_chunk(first_chunk_in_tree_index_1);
+        assert_eq!(keys.cache.get().0, U256::one());
+        assert_eq!(key, Key::from_stem(TestHasher::stem([7u8; 32], U256::one()), 0));
+    }
+
+    #[test]
+    fn handles_tree_indices_far_beyond_u64() {
+        // A keccak256-derived mapping/array storage slot lands on a
+        // tree-index spread over the full 256-bit range; this must not
+        // panic or truncate.
+        let keys = AccountKeys::new::<TestHasher>([7u8; 32]);
+        let huge_tree_index = U256::MAX / 2;
+        let key = keys.key(huge_tree_index, 3);
+        assert_eq!(
+            key,
+            Key::from_stem(TestHasher::stem([7u8; 32], huge_tree_index), 3)
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use ethereum_types::Address;
+
+/// A plain 20-byte Ethereum address, as found in genesis allocations and RPC responses.
+pub type Address20 = Address;
+
+/// The 32-byte address used as the root of the EIP-6800 tree-key derivation.
+///
+/// Per EIP-6800, a `Address20` is embedded into the low 20 bytes of a 32-byte
+/// value with the high 12 bytes left as zero.
+pub type Address32 = [u8; 32];
+
+/// Left-pads a 20-byte address with zeroes to produce the 32-byte address
+/// used when deriving Verkle tree keys.
+pub fn addr20_to_addr32(address: Address20) -> Address32 {
+    let mut address32 = [0u8; 32];
+    address32[12..].copy_from_slice(address.as_bytes());
+    address32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn pads_with_leading_zeroes() {
+        let address = Address20::from_str("0x0000000000000000000000000000000000000001").unwrap();
+        let address32 = addr20_to_addr32(address);
+        assert_eq!(&address32[..12], &[0u8; 12]);
+        assert_eq!(address32[31], 1);
+    }
+}
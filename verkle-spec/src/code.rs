@@ -0,0 +1,40 @@
+//! Splitting of raw bytecode into the 32-byte chunks the tree stores it as.
+//!
+//! Deriving the tree key of a chunk is not done through a dedicated type
+//! here: [`crate::AccountKeys::code_chunk`]/[`crate::AccountKeys::code_chunk_keys`]
+//! already do that cheaply for one chunk or a contiguous run of them, by
+//! amortizing the stem commitment across the run instead of recomputing it
+//! per chunk.
+
+/// Splits raw bytecode into the 32-byte chunks the tree stores it as: a
+/// reserved format byte followed by 31 bytes of code, with the final chunk
+/// zero-padded.
+///
+/// This mirrors the EIP-6800 "chunkification" used to lay code out in the
+/// tree. (It does not yet track PUSH-data continuation across chunk
+/// boundaries in the format byte; every chunk's format byte is `0`.)
+pub fn chunkify_code(code: Vec<u8>) -> Vec<[u8; 32]> {
+    const CHUNK_BYTES: usize = 31;
+
+    let mut chunks = Vec::with_capacity((code.len() + CHUNK_BYTES - 1) / CHUNK_BYTES);
+    for chunk_bytes in code.chunks(CHUNK_BYTES) {
+        let mut chunk = [0u8; 32];
+        chunk[1..1 + chunk_bytes.len()].copy_from_slice(chunk_bytes);
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunkifies_short_code_into_single_padded_chunk() {
+        let code = vec![0x60, 0x01, 0x60, 0x02];
+        let chunks = chunkify_code(code.clone());
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(&chunks[0][1..1 + code.len()], code.as_slice());
+        assert_eq!(&chunks[0][1 + code.len()..], &[0u8; 32 - 1 - 4][..]);
+    }
+}
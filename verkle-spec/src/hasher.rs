@@ -0,0 +1,28 @@
+use keccak_hash::keccak;
+
+use crate::{Address32, U256};
+
+/// Computes the EIP-6800 tree-key "stem" for a given 32-byte address and
+/// tree-index: the first 31 bytes of `commit(address, tree_index)`.
+///
+/// `tree_index` is a full 256-bit value, not a small counter: storage slots
+/// derived from a `keccak256` mapping/array layout (i.e. almost all real
+/// contract storage) are spread uniformly over the whole `U256` range, so
+/// the tree-index they land on can be arbitrarily large.
+///
+/// The real Verkle-crypto backend commits with a Pedersen hash over the
+/// Banderwagon curve; this crate only needs the shape of that function
+/// (one stem per `(address, tree_index)` pair), so implementations are free
+/// to swap in whatever commitment scheme the rest of the trie uses.
+pub trait Hasher {
+    /// Derives the 31-byte stem shared by every sub-index of `(address, tree_index)`.
+    fn stem(address: Address32, tree_index: U256) -> [u8; 31] {
+        let mut preimage = [0u8; 64];
+        preimage[..32].copy_from_slice(&address);
+        tree_index.to_big_endian(&mut preimage[32..]);
+        let digest = keccak(preimage);
+        let mut stem = [0u8; 31];
+        stem.copy_from_slice(&digest.0[..31]);
+        stem
+    }
+}
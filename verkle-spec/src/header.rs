@@ -0,0 +1,36 @@
+use crate::{AccountKeys, Address32, Hasher, Key};
+
+/// Derives the tree keys of an account's header fields (version, balance,
+/// nonce, code hash and code size), all of which live under tree-index 0
+/// and so share a single stem, computed once in [`Header::new`].
+pub struct Header {
+    keys: AccountKeys,
+}
+
+impl Header {
+    pub fn new<H: Hasher>(address: Address32) -> Self {
+        Header {
+            keys: AccountKeys::new::<H>(address),
+        }
+    }
+
+    pub fn version(&self) -> Key {
+        self.keys.version()
+    }
+
+    pub fn balance(&self) -> Key {
+        self.keys.balance()
+    }
+
+    pub fn nonce(&self) -> Key {
+        self.keys.nonce()
+    }
+
+    pub fn code_keccak(&self) -> Key {
+        self.keys.code_keccak()
+    }
+
+    pub fn code_size(&self) -> Key {
+        self.keys.code_size()
+    }
+}
@@ -0,0 +1,18 @@
+/// A raw, 32-byte Verkle tree key.
+///
+/// This is the only vocabulary that [`verkle_trie::Trie`](../verkle_trie/struct.Trie.html)
+/// understands; everything in this crate exists to compute the right `Key`
+/// for a given piece of account or storage state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key(pub [u8; 32]);
+
+impl Key {
+    /// Builds a key from a 31-byte stem and a 1-byte sub-index, as defined by
+    /// EIP-6800: `key = stem || sub_index`.
+    pub fn from_stem(stem: [u8; 31], sub_index: u8) -> Self {
+        let mut key = [0u8; 32];
+        key[..31].copy_from_slice(&stem);
+        key[31] = sub_index;
+        Key(key)
+    }
+}
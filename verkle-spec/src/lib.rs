@@ -0,0 +1,23 @@
+//! Mapping of Ethereum-style accounts and storage onto the EIP-6800 Verkle
+//! tree key layout.
+//!
+//! This crate knows nothing about trees or commitments beyond producing the
+//! right 32-byte [`Key`] for a given address, header field, code chunk or
+//! storage slot; [`verkle_trie`](../verkle_trie/index.html) is the layer
+//! that actually stores values under those keys.
+
+mod account_keys;
+mod address;
+pub mod code;
+mod hasher;
+mod header;
+mod key;
+mod storage;
+
+pub use account_keys::AccountKeys;
+pub use address::{addr20_to_addr32, Address20, Address32};
+pub use ethereum_types::{H256, U256};
+pub use hasher::Hasher;
+pub use header::Header;
+pub use key::Key;
+pub use storage::Storage;
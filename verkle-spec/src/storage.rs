@@ -0,0 +1,103 @@
+use crate::{
+    account_keys::{CODE_OFFSET, VERKLE_NODE_WIDTH},
+    AccountKeys, Address32, Hasher, Key, U256,
+};
+
+/// Sub-index within tree-index 0 of storage slot 0; slots
+/// `0..CODE_OFFSET - HEADER_STORAGE_OFFSET` occupy sub-indices from here up
+/// to `CODE_OFFSET` and so share the account's header stem.
+const HEADER_STORAGE_OFFSET: u64 = 64;
+
+/// Derives the tree key of a single 32-byte storage slot.
+pub struct Storage {
+    storage_key: U256,
+    keys: AccountKeys,
+}
+
+impl Storage {
+    pub fn new<H: Hasher>(address: Address32, storage_key: U256) -> Self {
+        Storage {
+            storage_key,
+            keys: AccountKeys::new::<H>(address),
+        }
+    }
+
+    pub fn storage_slot(&self) -> Key {
+        // Per EIP-6800: slots `0..CODE_OFFSET - HEADER_STORAGE_OFFSET` live
+        // alongside the header, everything else is offset into
+        // `MAIN_STORAGE_OFFSET = 256**31`.
+        let (tree_index, sub_index) = if self.storage_key < U256::from(CODE_OFFSET - HEADER_STORAGE_OFFSET) {
+            let position = U256::from(HEADER_STORAGE_OFFSET) + self.storage_key;
+            (
+                position / U256::from(VERKLE_NODE_WIDTH),
+                (position % U256::from(VERKLE_NODE_WIDTH)).as_u64() as u8,
+            )
+        } else {
+            // `MAIN_STORAGE_OFFSET + storage_key` does not fit in a `U256`
+            // for `storage_key` close to `U256::MAX` (keccak256-derived
+            // mapping/array slots are spread uniformly over the whole
+            // 256-bit range, so this is routinely hit, not an edge case) —
+            // adding it outright overflows. `MAIN_STORAGE_OFFSET` is itself
+            // an exact multiple of `VERKLE_NODE_WIDTH`, so the sum's
+            // tree-index and sub-index can be derived without ever
+            // materializing the sum itself.
+            let tree_index =
+                main_storage_offset_tree_index() + self.storage_key / U256::from(VERKLE_NODE_WIDTH);
+            let sub_index = (self.storage_key % U256::from(VERKLE_NODE_WIDTH)).as_u64() as u8;
+            (tree_index, sub_index)
+        };
+
+        self.keys.key(tree_index, sub_index)
+    }
+}
+
+/// `256**30`, i.e. `MAIN_STORAGE_OFFSET / VERKLE_NODE_WIDTH`: the tree-index
+/// contribution of `MAIN_STORAGE_OFFSET = 256**31`. Its sub-index
+/// contribution is always zero, since `256**31` divides evenly by
+/// `VERKLE_NODE_WIDTH = 256`.
+fn main_storage_offset_tree_index() -> U256 {
+    U256::from(256u64).pow(U256::from(30u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestHasher;
+    impl Hasher for TestHasher {}
+
+    #[test]
+    fn header_range_storage_key_shares_the_header_stem() {
+        let storage = Storage::new::<TestHasher>([1u8; 32], U256::from(5));
+        let keys = AccountKeys::new::<TestHasher>([1u8; 32]);
+        assert_eq!(
+            storage.storage_slot(),
+            keys.key(U256::zero(), (HEADER_STORAGE_OFFSET + 5) as u8)
+        );
+    }
+
+    #[test]
+    fn does_not_overflow_for_storage_keys_near_u256_max() {
+        // A keccak256-derived mapping/array slot can land anywhere in the
+        // 256-bit range, including right up against `U256::MAX`, where
+        // `MAIN_STORAGE_OFFSET + storage_key` would overflow a naive `U256`
+        // addition.
+        let storage = Storage::new::<TestHasher>([1u8; 32], U256::MAX);
+        // Must not panic.
+        let _ = storage.storage_slot();
+    }
+
+    #[test]
+    fn main_storage_range_keys_agree_with_a_direct_calculation() {
+        let storage_key = U256::from(12345u64);
+        let storage = Storage::new::<TestHasher>([1u8; 32], storage_key);
+        let keys = AccountKeys::new::<TestHasher>([1u8; 32]);
+
+        let position = main_storage_offset_tree_index() * U256::from(VERKLE_NODE_WIDTH) + storage_key;
+        let expected = keys.key(
+            position / U256::from(VERKLE_NODE_WIDTH),
+            (position % U256::from(VERKLE_NODE_WIDTH)).as_u64() as u8,
+        );
+        assert_eq!(storage.storage_slot(), expected);
+    }
+}
@@ -7,7 +7,8 @@ use serde::Deserialize;
 use serde_json::json;
 use std::{fs::File, io::BufReader, str::FromStr, sync::Mutex};
 use verkle_spec::{
-    addr20_to_addr32, code::chunkify_code, Address20, Code, Hasher, Header, Storage, H256, U256,
+    addr20_to_addr32, code::chunkify_code, AccountKeys, Address20, Hasher, Header, Storage, H256,
+    U256,
 };
 use verkle_trie::{database::memory_db::MemoryDb, Trie, TrieTrait, Value, VerkleConfig};
 
@@ -81,16 +82,13 @@ fn state_root() -> Result<()> {
                     ]
                     .into_iter(),
                 );
-                trie.insert(chunkify_code(code).into_iter().enumerate().map(
-                    |(chunk_id, code_chunk)| {
-                        (
-                            Code::new::<DefaultHasher>(address, U256::from(chunk_id))
-                                .code_chunk()
-                                .0,
-                            code_chunk,
-                        )
-                    },
-                ));
+                let chunks = chunkify_code(code);
+                let keys = AccountKeys::new::<DefaultHasher>(address);
+                trie.insert(
+                    keys.code_chunk_keys(0, chunks.len() as u64)
+                        .zip(chunks)
+                        .map(|((_chunk_id, key), chunk)| (key.0, chunk)),
+                );
 
                 let Some(storage) = account_state.get("storage") else {
                     continue;
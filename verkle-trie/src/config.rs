@@ -0,0 +1,16 @@
+use crate::database::Database;
+
+/// Shared configuration that every [`crate::Trie`] over a given `Db` is
+/// built from. Cloning clones `Db`: for [`crate::database::RocksDb`] that
+/// shares the underlying database handle, while for
+/// [`crate::database::memory_db::MemoryDb`] it copies every stored entry.
+#[derive(Clone)]
+pub struct VerkleConfig<Db> {
+    pub(crate) db: Db,
+}
+
+impl<Db: Database> VerkleConfig<Db> {
+    pub fn new(db: Db) -> Self {
+        VerkleConfig { db }
+    }
+}
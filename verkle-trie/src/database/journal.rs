@@ -0,0 +1,187 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::Database;
+
+/// A change to a single key, as recorded by [`JournalDb`]: either a new
+/// value, or `None` if the key was removed.
+type Change = Option<[u8; 32]>;
+
+/// A journaling overlay over a [`Database`], modeled on OpenEthereum's
+/// `journaldb`.
+///
+/// Writes accumulate in an in-memory overlay rather than touching the
+/// backing database directly. [`JournalDb::commit`] flushes the overlay to
+/// the backing database, tagged with a caller-chosen, strictly increasing
+/// era (e.g. a block number), and records enough information to undo it;
+/// [`JournalDb::revert`] pops the most recently committed eras back off (by
+/// count, not by era number — there is no "revert to era N") and restores
+/// the values they overwrote.
+pub struct JournalDb<Db> {
+    backing: Db,
+    overlay: HashMap<[u8; 32], Change>,
+    /// Eras committed so far, most recent first, each paired with the
+    /// pre-commit value of every key it touched (so it can be undone).
+    journal: VecDeque<(u64, HashMap<[u8; 32], Change>)>,
+    /// The era passed to the most recent [`commit`](Self::commit) call, used
+    /// only to reject non-increasing eras.
+    last_committed_era: Option<u64>,
+}
+
+impl<Db: Database> JournalDb<Db> {
+    pub fn new(backing: Db) -> Self {
+        JournalDb {
+            backing,
+            overlay: HashMap::new(),
+            journal: VecDeque::new(),
+            last_committed_era: None,
+        }
+    }
+
+    /// Flushes the pending overlay into the backing database, recording it
+    /// under `era` so it can later be [`revert`](Self::revert)ed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `era` is not strictly greater than the era passed to the
+    /// previous `commit` call: eras are expected to track something
+    /// monotonic, like a block number, and committing the same era twice or
+    /// out of order would make the journal's undo history meaningless.
+    pub fn commit(&mut self, era: u64) {
+        assert!(
+            self.last_committed_era.map_or(true, |last| era > last),
+            "eras must be committed in strictly increasing order (got {era}, last committed was {:?})",
+            self.last_committed_era,
+        );
+        self.last_committed_era = Some(era);
+
+        let overlay = std::mem::take(&mut self.overlay);
+        let mut undo = HashMap::with_capacity(overlay.len());
+
+        for (key, change) in overlay {
+            undo.insert(key, self.backing.get(&key));
+            match change {
+                Some(value) => {
+                    self.backing.insert(key, value);
+                }
+                None => {
+                    self.backing.remove(&key);
+                }
+            }
+        }
+
+        self.journal.push_front((era, undo));
+    }
+
+    /// Undoes the `eras` most recently committed eras, restoring whatever
+    /// each key held immediately before that era was committed.
+    pub fn revert(&mut self, eras: u64) {
+        for _ in 0..eras {
+            let Some((_, undo)) = self.journal.pop_front() else {
+                break;
+            };
+            for (key, previous) in undo {
+                match previous {
+                    Some(value) => {
+                        self.backing.insert(key, value);
+                    }
+                    None => {
+                        self.backing.remove(&key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<Db: Database> Database for JournalDb<Db> {
+    fn get(&self, key: &[u8; 32]) -> Option<[u8; 32]> {
+        match self.overlay.get(key) {
+            Some(change) => *change,
+            None => self.backing.get(key),
+        }
+    }
+
+    fn insert(&mut self, key: [u8; 32], value: [u8; 32]) -> Option<[u8; 32]> {
+        let previous = self.get(&key);
+        self.overlay.insert(key, Some(value));
+        previous
+    }
+
+    fn remove(&mut self, key: &[u8; 32]) -> Option<[u8; 32]> {
+        let previous = self.get(key);
+        self.overlay.insert(*key, None);
+        previous
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = ([u8; 32], [u8; 32])> + '_> {
+        let backing_overlaid = self.backing.iter().filter_map(|(key, value)| {
+            match self.overlay.get(&key) {
+                Some(Some(overlaid_value)) => Some((key, *overlaid_value)),
+                Some(None) => None,
+                None => Some((key, value)),
+            }
+        });
+        let inserted_only_in_overlay = self
+            .overlay
+            .iter()
+            .filter(move |(key, change)| change.is_some() && self.backing.get(key).is_none())
+            .map(|(key, change)| (*key, change.unwrap()));
+
+        Box::new(backing_overlaid.chain(inserted_only_in_overlay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::memory_db::MemoryDb;
+
+    #[test]
+    fn commit_then_revert_restores_previous_value() {
+        let mut db = JournalDb::new(MemoryDb::new());
+
+        db.insert([1; 32], [10; 32]);
+        db.commit(0);
+        assert_eq!(db.get(&[1; 32]), Some([10; 32]));
+
+        db.insert([1; 32], [20; 32]);
+        db.commit(1);
+        assert_eq!(db.get(&[1; 32]), Some([20; 32]));
+
+        db.revert(1);
+        assert_eq!(db.get(&[1; 32]), Some([10; 32]));
+    }
+
+    #[test]
+    fn revert_of_initial_insert_removes_the_key() {
+        let mut db = JournalDb::new(MemoryDb::new());
+
+        db.insert([1; 32], [10; 32]);
+        db.commit(0);
+
+        db.revert(1);
+        assert_eq!(db.get(&[1; 32]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing order")]
+    fn committing_the_same_era_twice_panics() {
+        let mut db = JournalDb::new(MemoryDb::new());
+
+        db.insert([1; 32], [10; 32]);
+        db.commit(5);
+        db.insert([1; 32], [20; 32]);
+        db.commit(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing order")]
+    fn committing_an_earlier_era_panics() {
+        let mut db = JournalDb::new(MemoryDb::new());
+
+        db.insert([1; 32], [10; 32]);
+        db.commit(5);
+        db.insert([1; 32], [20; 32]);
+        db.commit(4);
+    }
+}
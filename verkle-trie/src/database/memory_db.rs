@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use super::Database;
+
+/// An in-memory, non-persistent [`Database`]. Useful for tests and for
+/// genesis construction that will be handed off to a persistent backend.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryDb {
+    storage: HashMap<[u8; 32], [u8; 32]>,
+}
+
+impl MemoryDb {
+    pub fn new() -> Self {
+        MemoryDb::default()
+    }
+}
+
+impl Database for MemoryDb {
+    fn get(&self, key: &[u8; 32]) -> Option<[u8; 32]> {
+        self.storage.get(key).copied()
+    }
+
+    fn insert(&mut self, key: [u8; 32], value: [u8; 32]) -> Option<[u8; 32]> {
+        self.storage.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &[u8; 32]) -> Option<[u8; 32]> {
+        self.storage.remove(key)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = ([u8; 32], [u8; 32])> + '_> {
+        Box::new(self.storage.iter().map(|(key, value)| (*key, *value)))
+    }
+}
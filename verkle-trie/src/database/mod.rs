@@ -0,0 +1,23 @@
+//! Storage backends for [`crate::Trie`].
+
+pub mod journal;
+pub mod memory_db;
+pub mod rocks_db;
+
+pub use journal::JournalDb;
+pub use rocks_db::RocksDb;
+
+/// A key/value store that a [`crate::Trie`] can be layered on top of.
+///
+/// Keys and values are both raw 32-byte Verkle tree words; any encoding of
+/// Ethereum-level concepts (accounts, storage slots, ...) happens above this
+/// trait, in `verkle-spec`.
+pub trait Database {
+    fn get(&self, key: &[u8; 32]) -> Option<[u8; 32]>;
+    fn insert(&mut self, key: [u8; 32], value: [u8; 32]) -> Option<[u8; 32]>;
+    fn remove(&mut self, key: &[u8; 32]) -> Option<[u8; 32]>;
+
+    /// Iterates over every key/value pair currently stored. Used by
+    /// [`crate::snapshot`] to walk the whole tree for export.
+    fn iter(&self) -> Box<dyn Iterator<Item = ([u8; 32], [u8; 32])> + '_>;
+}
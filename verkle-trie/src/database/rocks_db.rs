@@ -0,0 +1,143 @@
+use std::{path::Path, sync::Arc};
+
+use anyhow::{anyhow, Context, Result};
+use rocksdb::DB;
+
+use super::Database;
+
+/// A persistent, RocksDB-backed [`Database`].
+///
+/// Cloning a `RocksDb` is cheap and shares the same underlying column
+/// family, so it can be handed to a [`crate::VerkleConfig`] the same way
+/// [`super::memory_db::MemoryDb`] is.
+#[derive(Clone)]
+pub struct RocksDb {
+    db: Arc<DB>,
+}
+
+impl RocksDb {
+    /// Opens (creating if necessary) a RocksDB database at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(RocksDb {
+            db: Arc::new(DB::open_default(path).context("opening rocksdb")?),
+        })
+    }
+
+    /// Fallible equivalent of [`Database::get`].
+    ///
+    /// Disk I/O can fail for reasons that have nothing to do with a bug in
+    /// this crate (a full disk, corruption, lock contention, ...); callers
+    /// that care about that distinction should use this instead of going
+    /// through the infallible [`Database`] trait, which treats any failure
+    /// here as fatal.
+    pub fn try_get(&self, key: &[u8; 32]) -> Result<Option<[u8; 32]>> {
+        self.db
+            .get(key)
+            .context("rocksdb get")?
+            .map(|value| {
+                value
+                    .try_into()
+                    .map_err(|_| anyhow!("stored value is not 32 bytes"))
+            })
+            .transpose()
+    }
+
+    /// Fallible equivalent of [`Database::insert`].
+    pub fn try_insert(&mut self, key: [u8; 32], value: [u8; 32]) -> Result<Option<[u8; 32]>> {
+        let previous = self.try_get(&key)?;
+        self.db.put(key, value).context("rocksdb put")?;
+        Ok(previous)
+    }
+
+    /// Fallible equivalent of [`Database::remove`].
+    pub fn try_remove(&mut self, key: &[u8; 32]) -> Result<Option<[u8; 32]>> {
+        let previous = self.try_get(key)?;
+        self.db.delete(key).context("rocksdb delete")?;
+        Ok(previous)
+    }
+
+    /// Fallible equivalent of [`Database::iter`].
+    pub fn try_iter(&self) -> Result<Vec<([u8; 32], [u8; 32])>> {
+        self.db
+            .iterator(rocksdb::IteratorMode::Start)
+            .map(|entry| {
+                let (key, value) = entry.context("rocksdb iterator")?;
+                let key = key
+                    .as_ref()
+                    .try_into()
+                    .map_err(|_| anyhow!("stored key is not 32 bytes"))?;
+                let value = value
+                    .as_ref()
+                    .try_into()
+                    .map_err(|_| anyhow!("stored value is not 32 bytes"))?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+}
+
+impl Database for RocksDb {
+    fn get(&self, key: &[u8; 32]) -> Option<[u8; 32]> {
+        self.try_get(key).expect("rocksdb get")
+    }
+
+    fn insert(&mut self, key: [u8; 32], value: [u8; 32]) -> Option<[u8; 32]> {
+        self.try_insert(key, value).expect("rocksdb insert")
+    }
+
+    fn remove(&mut self, key: &[u8; 32]) -> Option<[u8; 32]> {
+        self.try_remove(key).expect("rocksdb remove")
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = ([u8; 32], [u8; 32])> + '_> {
+        Box::new(self.try_iter().expect("rocksdb iterator").into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{database::JournalDb, Trie, TrieTrait, VerkleConfig};
+
+    /// Exercises the actual durability workflow this backend exists for:
+    /// build a trie over a journaled `RocksDb`, commit some writes, undo the
+    /// most recent one, then reopen the same on-disk database in a fresh
+    /// handle (standing in for a process restart) and check the data —
+    /// including the undo — survived.
+    ///
+    /// [`JournalDb`]'s undo bookkeeping is only ever kept in memory (see
+    /// `journal.rs`), so reverting has to happen before the reload; what's
+    /// persisted is whatever `commit`/`revert` have already written through
+    /// to the backing `RocksDb`.
+    #[test]
+    fn persists_across_reload_and_keeps_a_reverted_commit_undone() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_a = [1u8; 32];
+        let key_b = [2u8; 32];
+
+        let expected_root = {
+            let db = RocksDb::open(dir.path()).unwrap();
+            let mut trie = Trie::new(VerkleConfig::new(JournalDb::new(db)));
+
+            trie.insert_single(key_a, [10u8; 32]);
+            trie.config.db.commit(0);
+
+            trie.insert_single(key_a, [20u8; 32]);
+            trie.insert_single(key_b, [99u8; 32]);
+            trie.config.db.commit(1);
+            assert_eq!(trie.get(&key_a), Some([20u8; 32]));
+
+            trie.config.db.revert(1);
+            assert_eq!(trie.get(&key_a), Some([10u8; 32]));
+            assert_eq!(trie.get(&key_b), None);
+
+            trie.root_hash()
+        };
+
+        let db = RocksDb::open(dir.path()).unwrap();
+        let trie = Trie::new(VerkleConfig::new(JournalDb::new(db)));
+        assert_eq!(trie.get(&key_a), Some([10u8; 32]));
+        assert_eq!(trie.get(&key_b), None);
+        assert_eq!(trie.root_hash(), expected_root);
+    }
+}
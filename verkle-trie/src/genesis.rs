@@ -0,0 +1,244 @@
+//! Builds a populated [`Trie`] from genesis state, accepting either the
+//! geth-style `{"alloc": {...}}` schema or the OpenEthereum/Parity chainspec
+//! schema (`{"params": ..., "genesis": ..., "accounts": {...}}`), as seen in
+//! e.g. their `morden.json`/`frontier.json`.
+
+use std::{io::Read, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use ark_serialize::CanonicalSerialize;
+use hex::FromHex;
+use keccak_hash::{keccak, KECCAK_EMPTY};
+use serde::Deserialize;
+use serde_json::Value as Json;
+use verkle_spec::{
+    addr20_to_addr32, code::chunkify_code, AccountKeys, Address20, Hasher, Header, Storage, H256,
+    U256,
+};
+
+use crate::{database::memory_db::MemoryDb, Trie, TrieTrait, Value, VerkleConfig};
+
+/// Builds a [`Trie`] from a genesis JSON document, auto-detecting whether it
+/// is a geth `alloc` document or an OpenEthereum/Parity chainspec.
+pub fn build_trie<H: Hasher>(reader: impl Read) -> Result<Trie<MemoryDb>> {
+    let genesis: Json = serde_json::from_reader(reader).context("parsing genesis JSON")?;
+    let accounts = accounts_object(&genesis)?;
+
+    let mut trie = Trie::new(VerkleConfig::new(MemoryDb::new()));
+    for (address, account) in accounts {
+        insert_account::<H>(&mut trie, address, account)?;
+    }
+
+    Ok(trie)
+}
+
+/// Builds a [`Trie`] from genesis and returns its root hash, for comparison
+/// against a chain's expected genesis state root.
+pub fn state_root<H: Hasher>(reader: impl Read) -> Result<H256> {
+    let trie = build_trie::<H>(reader)?;
+    let mut root = H256::zero();
+    trie.root_hash().serialize_compressed(root.0.as_mut_slice())?;
+    Ok(root)
+}
+
+/// Finds the `address -> account` map, regardless of which genesis schema
+/// it is nested in.
+fn accounts_object(genesis: &Json) -> Result<&serde_json::Map<String, Json>> {
+    let root = genesis.as_object().context("genesis is not a JSON object")?;
+
+    // OpenEthereum/Parity chainspec: accounts live under a top-level `accounts`
+    // key, alongside `params`/`genesis`.
+    if let Some(accounts) = root.get("accounts") {
+        return accounts
+            .as_object()
+            .context("chainspec `accounts` is not an object");
+    }
+
+    // geth-style genesis: accounts live under `alloc`.
+    if let Some(alloc) = root.get("alloc") {
+        return alloc.as_object().context("genesis `alloc` is not an object");
+    }
+
+    bail!("genesis JSON has neither a geth-style `alloc` nor a chainspec `accounts` field")
+}
+
+fn insert_account<H: Hasher>(
+    trie: &mut Trie<MemoryDb>,
+    address: &str,
+    account: &Json,
+) -> Result<()> {
+    let account = account
+        .as_object()
+        .with_context(|| format!("account {address} is not an object"))?;
+
+    // Parity/OpenEthereum precompile entries carry a `builtin` descriptor
+    // (real chainspecs, e.g. `frontier.json`, also give them a nominal
+    // `balance` so EIP-161 doesn't prune them as empty) rather than real
+    // account state; skip them on `builtin` alone.
+    if account.contains_key("builtin") {
+        return Ok(());
+    }
+
+    let address = addr20_to_addr32(Address20::from_str(address)?);
+    let header = Header::new::<H>(address);
+
+    let balance = match account.get("balance") {
+        Some(balance) => parse_uint(balance)?,
+        None => U256::zero(),
+    };
+    let nonce = match account.get("nonce") {
+        Some(nonce) => parse_uint(nonce)?,
+        None => U256::zero(),
+    };
+
+    trie.insert(
+        [
+            (header.version().0, to_value(U256::zero())),
+            (header.balance().0, to_value(balance)),
+            (header.nonce().0, to_value(nonce)),
+        ]
+        .into_iter(),
+    );
+
+    match account.get("code") {
+        Some(code) if code.as_str().is_some_and(|code| !code.is_empty()) => {
+            let code = code.as_str().unwrap();
+            let code = code.strip_prefix("0x").unwrap_or(code);
+            let code = <Vec<u8>>::from_hex(code)?;
+
+            trie.insert(
+                [
+                    (header.code_keccak().0, keccak(&code).0),
+                    (header.code_size().0, to_value(U256::from(code.len()))),
+                ]
+                .into_iter(),
+            );
+            let chunks = chunkify_code(code);
+            let keys = AccountKeys::new::<H>(address);
+            trie.insert(
+                keys.code_chunk_keys(0, chunks.len() as u64)
+                    .zip(chunks)
+                    .map(|((_chunk_id, key), chunk)| (key.0, chunk)),
+            );
+
+            if let Some(storage) = account.get("storage").and_then(Json::as_object) {
+                for (key, value) in storage {
+                    let key = U256::from_str(key).with_context(|| format!("storage key {key}"))?;
+                    let value = U256::deserialize(value).context("storage value")?;
+                    trie.insert_single(
+                        Storage::new::<H>(address, key).storage_slot().0,
+                        to_value(value),
+                    );
+                }
+            }
+        }
+        _ => {
+            trie.insert_single(header.code_keccak().0, KECCAK_EMPTY.0);
+        }
+    }
+
+    Ok(())
+}
+
+fn to_value(u256: U256) -> Value {
+    let mut value = Value::default();
+    u256.to_little_endian(&mut value);
+    value
+}
+
+/// Parses a balance/nonce value that may be hex (`"0x..."`), decimal
+/// (`"123"`) or a bare JSON number.
+fn parse_uint(value: &Json) -> Result<U256> {
+    match value {
+        Json::Number(n) => Ok(U256::from(n.as_u64().context("uint does not fit in u64")?)),
+        Json::String(s) => {
+            if let Some(hex) = s.strip_prefix("0x") {
+                Ok(U256::from_str_radix(hex, 16)?)
+            } else {
+                Ok(U256::from_str_radix(s, 10)?)
+            }
+        }
+        other => bail!("expected a numeric value, got {other}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use serde_json::json;
+
+    use super::*;
+
+    struct TestHasher;
+    impl Hasher for TestHasher {}
+
+    fn balance_of(trie: &Trie<MemoryDb>, address: &str) -> Option<U256> {
+        let address = addr20_to_addr32(Address20::from_str(address).unwrap());
+        trie.get(&Header::new::<TestHasher>(address).balance().0)
+            .map(|value| U256::from_little_endian(&value))
+    }
+
+    #[test]
+    fn parses_geth_style_alloc_with_hex_balance() {
+        let genesis = json!({
+            "alloc": {
+                "0x0000000000000000000000000000000000000001": { "balance": "0x64" },
+            }
+        });
+        let trie = build_trie::<TestHasher>(Cursor::new(genesis.to_string())).unwrap();
+
+        assert_eq!(
+            balance_of(&trie, "0x0000000000000000000000000000000000000001"),
+            Some(U256::from(100))
+        );
+    }
+
+    #[test]
+    fn parses_chainspec_style_accounts_with_decimal_balance_and_defaulted_nonce() {
+        let genesis = json!({
+            "params": {},
+            "genesis": {},
+            "accounts": {
+                "0x0000000000000000000000000000000000000002": { "balance": "100" },
+            }
+        });
+        let trie = build_trie::<TestHasher>(Cursor::new(genesis.to_string())).unwrap();
+
+        assert_eq!(
+            balance_of(&trie, "0x0000000000000000000000000000000000000002"),
+            Some(U256::from(100))
+        );
+
+        let address = addr20_to_addr32(
+            Address20::from_str("0x0000000000000000000000000000000000000002").unwrap(),
+        );
+        assert_eq!(
+            trie.get(&Header::new::<TestHasher>(address).nonce().0)
+                .map(|value| U256::from_little_endian(&value)),
+            Some(U256::zero())
+        );
+    }
+
+    #[test]
+    fn skips_builtin_precompile_entries_even_with_a_nominal_balance() {
+        // As in real chainspecs (e.g. `frontier.json`): precompiles carry a
+        // `builtin` descriptor *and* a nominal balance of `"1"`.
+        let genesis = json!({
+            "params": {},
+            "genesis": {},
+            "accounts": {
+                "0x0000000000000000000000000000000000000001": {
+                    "balance": "1",
+                    "builtin": { "name": "ecrecover" },
+                },
+            }
+        });
+        let trie = build_trie::<TestHasher>(Cursor::new(genesis.to_string())).unwrap();
+
+        assert_eq!(
+            balance_of(&trie, "0x0000000000000000000000000000000000000001"),
+            None
+        );
+    }
+}
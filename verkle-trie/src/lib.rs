@@ -0,0 +1,20 @@
+//! A Verkle tree over a 32-byte key/value space, with pluggable storage
+//! backends.
+//!
+//! This crate is deliberately ignorant of what the keys and values mean;
+//! `verkle-spec` maps Ethereum-level concepts (accounts, storage slots,
+//! code) onto the raw keys this crate stores.
+
+pub mod config;
+pub mod database;
+pub mod genesis;
+mod snapshot;
+pub mod state;
+mod trie;
+
+pub use config::VerkleConfig;
+pub use state::{Account, State};
+pub use trie::{Trie, TrieTrait};
+
+/// A 32-byte tree value.
+pub type Value = [u8; 32];
@@ -0,0 +1,151 @@
+//! Streaming, RLP-encoded import/export of a [`Trie`]'s full contents,
+//! mirroring how OpenEthereum snapshots state accounts as RLP records.
+//!
+//! Each record is a length-prefixed RLP tuple of a 31-byte stem and the
+//! `(sub_index, value)` pairs stored under it, so that every key sharing a
+//! stem is written (and read back) together.
+
+use std::{
+    collections::BTreeMap,
+    io::{Read, Write},
+};
+
+use anyhow::{bail, Context, Result};
+use ark_serialize::CanonicalSerialize;
+use rlp::{Rlp, RlpStream};
+use verkle_spec::H256;
+
+use crate::{config::VerkleConfig, database::Database, Trie, TrieTrait};
+
+impl<Db: Database> Trie<Db> {
+    /// Writes every key/value pair in the tree to `writer`, one
+    /// length-prefixed RLP record per stem.
+    pub fn export_snapshot(&self, mut writer: impl Write) -> Result<()> {
+        let mut by_stem: BTreeMap<[u8; 31], Vec<(u8, [u8; 32])>> = BTreeMap::new();
+        for (key, value) in self.config.db.iter() {
+            let mut stem = [0u8; 31];
+            stem.copy_from_slice(&key[..31]);
+            by_stem.entry(stem).or_default().push((key[31], value));
+        }
+
+        for (stem, mut entries) in by_stem {
+            entries.sort_unstable_by_key(|(sub_index, _)| *sub_index);
+
+            let mut stream = RlpStream::new_list(2);
+            stream.append(&stem.as_slice());
+            stream.begin_list(entries.len());
+            for (sub_index, value) in &entries {
+                stream.begin_list(2);
+                stream.append(sub_index);
+                stream.append(&value.as_slice());
+            }
+            let record = stream.out();
+
+            writer.write_all(&(record.len() as u32).to_be_bytes())?;
+            writer.write_all(&record)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Db: Database + Clone> Trie<Db> {
+    /// Rebuilds a tree from a snapshot written by [`Trie::export_snapshot`],
+    /// inserting every key/value pair into `db`, and returns the resulting
+    /// root hash so the caller can check it against an expected one.
+    pub fn import_snapshot(mut reader: impl Read, db: &mut Db) -> Result<H256> {
+        let mut length_prefix = [0u8; 4];
+        loop {
+            match reader.read_exact(&mut length_prefix) {
+                Ok(()) => {}
+                Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(error) => return Err(error.into()),
+            }
+
+            let mut record = vec![0u8; u32::from_be_bytes(length_prefix) as usize];
+            reader.read_exact(&mut record)?;
+            import_record(&record, db)?;
+        }
+
+        let trie = Trie::new(VerkleConfig::new(db.clone()));
+        let mut root = H256::zero();
+        trie.root_hash().serialize_compressed(root.0.as_mut_slice())?;
+        Ok(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, io::Cursor};
+
+    use super::*;
+    use crate::database::memory_db::MemoryDb;
+
+    #[test]
+    fn round_trips_keys_and_values_across_multiple_stems() {
+        let mut trie = Trie::new(VerkleConfig::new(MemoryDb::new()));
+        // Several keys share a stem (to exercise grouping) and several don't
+        // (to exercise the per-stem record boundary).
+        let mut key = |stem_byte: u8, sub_index: u8| {
+            let mut key = [0u8; 32];
+            key[0] = stem_byte;
+            key[31] = sub_index;
+            key
+        };
+        trie.insert(
+            [
+                (key(1, 0), [1u8; 32]),
+                (key(1, 5), [2u8; 32]),
+                (key(2, 0), [3u8; 32]),
+                (key(3, 200), [4u8; 32]),
+            ]
+            .into_iter(),
+        );
+        let expected_root = trie.root_hash();
+
+        let mut buffer = Vec::new();
+        trie.export_snapshot(&mut buffer).unwrap();
+
+        let mut imported = MemoryDb::new();
+        let root = Trie::import_snapshot(Cursor::new(&buffer), &mut imported).unwrap();
+
+        let original: BTreeMap<_, _> = trie.config.db.iter().collect();
+        let round_tripped: BTreeMap<_, _> = imported.iter().collect();
+        assert_eq!(round_tripped, original);
+
+        let mut expected = H256::zero();
+        expected_root
+            .serialize_compressed(expected.0.as_mut_slice())
+            .unwrap();
+        assert_eq!(root, expected);
+    }
+}
+
+fn import_record(record: &[u8], db: &mut impl Database) -> Result<()> {
+    let rlp = Rlp::new(record);
+    if rlp.item_count()? != 2 {
+        bail!("snapshot record does not have the expected (stem, entries) shape");
+    }
+
+    let stem_bytes: Vec<u8> = rlp.val_at(0)?;
+    let stem: [u8; 31] = stem_bytes
+        .as_slice()
+        .try_into()
+        .context("snapshot stem is not 31 bytes")?;
+
+    for entry in rlp.at(1)?.iter() {
+        let sub_index: u8 = entry.val_at(0)?;
+        let value_bytes: Vec<u8> = entry.val_at(1)?;
+        let value: [u8; 32] = value_bytes
+            .as_slice()
+            .try_into()
+            .context("snapshot value is not 32 bytes")?;
+
+        let mut key = [0u8; 32];
+        key[..31].copy_from_slice(&stem);
+        key[31] = sub_index;
+        db.insert(key, value);
+    }
+
+    Ok(())
+}
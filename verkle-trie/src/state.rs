@@ -0,0 +1,370 @@
+//! A high-level, EIP-6800-key-layout-agnostic view of accounts and storage,
+//! sitting on top of the raw [`Trie`].
+//!
+//! Modeled on OpenEthereum's `ethcore::state::Account`: an [`Account`] is a
+//! plain bundle of balance/nonce/code/storage that callers can read and
+//! mutate freely, with the actual tree-key bookkeeping (and code
+//! chunkification) deferred to [`State::set_account`].
+
+use std::collections::BTreeMap;
+
+use keccak_hash::{keccak, KECCAK_EMPTY};
+use verkle_spec::{
+    addr20_to_addr32, code::chunkify_code, AccountKeys, Address20, Hasher, Header, Storage, H256,
+    U256,
+};
+
+use crate::{database::Database, Trie, TrieTrait, Value};
+
+fn to_value(u256: U256) -> Value {
+    let mut value = Value::default();
+    u256.to_little_endian(&mut value);
+    value
+}
+
+fn from_value(value: Value) -> U256 {
+    U256::from_little_endian(&value)
+}
+
+/// Number of 31-byte chunks `code_size` bytes of code are split into.
+fn chunk_count(code_size: U256) -> u64 {
+    (code_size.as_u64() + 30) / 31
+}
+
+/// An account's balance, nonce and code, plus any storage writes pending
+/// against it.
+///
+/// `Account` itself knows nothing about how it is laid out in the tree;
+/// it is read and written through [`State::get_account`] and
+/// [`State::set_account`].
+#[derive(Debug, Clone, Default)]
+pub struct Account {
+    pub balance: U256,
+    pub nonce: U256,
+    code_hash: H256,
+    code_size: U256,
+    code: Option<Vec<u8>>,
+    storage: BTreeMap<U256, U256>,
+}
+
+impl Account {
+    pub fn new(balance: U256, nonce: U256) -> Self {
+        Account {
+            balance,
+            nonce,
+            code_hash: KECCAK_EMPTY,
+            code_size: U256::zero(),
+            code: None,
+            storage: BTreeMap::new(),
+        }
+    }
+
+    pub fn code_hash(&self) -> H256 {
+        self.code_hash
+    }
+
+    pub fn code_size(&self) -> U256 {
+        self.code_size
+    }
+
+    /// The account's code, if it has been loaded (by [`State::get_account`])
+    /// or set (by [`Account::init_code`]) since this `Account` was created.
+    pub fn code(&self) -> Option<&[u8]> {
+        self.code.as_deref()
+    }
+
+    /// Sets the account's code, updating `code_hash`/`code_size` to match.
+    /// An empty `code` is equivalent to the account having no code at all.
+    pub fn init_code(&mut self, code: Vec<u8>) {
+        self.code_hash = if code.is_empty() {
+            KECCAK_EMPTY
+        } else {
+            keccak(&code)
+        };
+        self.code_size = U256::from(code.len());
+        self.code = Some(code);
+    }
+
+    /// Queues a storage write, to be applied on the next
+    /// [`State::set_account`].
+    pub fn set_storage(&mut self, key: U256, value: U256) {
+        self.storage.insert(key, value);
+    }
+}
+
+/// The high-level account/storage view over a [`Trie`].
+pub struct State<Db> {
+    trie: Trie<Db>,
+}
+
+impl<Db: Database> State<Db> {
+    pub fn new(trie: Trie<Db>) -> Self {
+        State { trie }
+    }
+
+    pub fn into_trie(self) -> Trie<Db> {
+        self.trie
+    }
+
+    /// Reads an account's header fields and, if it has code, the code
+    /// itself (by reassembling it from its chunks).
+    pub fn get_account<H: Hasher>(&self, address: Address20) -> Account {
+        let address = addr20_to_addr32(address);
+        let header = Header::new::<H>(address);
+
+        let balance = self
+            .trie
+            .get(&header.balance().0)
+            .map(from_value)
+            .unwrap_or_default();
+        let nonce = self
+            .trie
+            .get(&header.nonce().0)
+            .map(from_value)
+            .unwrap_or_default();
+        let code_size = self
+            .trie
+            .get(&header.code_size().0)
+            .map(from_value)
+            .unwrap_or_default();
+        let code_hash = self
+            .trie
+            .get(&header.code_keccak().0)
+            .map(H256)
+            .unwrap_or(KECCAK_EMPTY);
+
+        let code = if code_size.is_zero() {
+            None
+        } else {
+            Some(self.read_code::<H>(address, code_size))
+        };
+
+        Account {
+            balance,
+            nonce,
+            code_hash,
+            code_size,
+            code,
+            storage: BTreeMap::new(),
+        }
+    }
+
+    /// Writes an account's header fields, committing its code (if set) and
+    /// any queued storage writes.
+    ///
+    /// If the account previously had more code chunks than it does now
+    /// (code shrunk, or was cleared entirely), the leftover chunks are
+    /// removed rather than left dangling in the tree.
+    pub fn set_account<H: Hasher>(&mut self, address: Address20, account: Account) {
+        let address32 = addr20_to_addr32(address);
+        let header = Header::new::<H>(address32);
+
+        let previous_chunk_count = self
+            .trie
+            .get(&header.code_size().0)
+            .map(from_value)
+            .map(chunk_count)
+            .unwrap_or_default();
+
+        self.trie.insert(
+            [
+                (header.version().0, to_value(U256::zero())),
+                (header.balance().0, to_value(account.balance)),
+                (header.nonce().0, to_value(account.nonce)),
+                (header.code_keccak().0, account.code_hash.0),
+                (header.code_size().0, to_value(account.code_size)),
+            ]
+            .into_iter(),
+        );
+
+        if let Some(code) = account.code {
+            self.commit_code::<H>(address32, code);
+        }
+
+        let new_chunk_count = chunk_count(account.code_size);
+        if new_chunk_count < previous_chunk_count {
+            self.clear_code_chunks::<H>(address32, new_chunk_count, previous_chunk_count);
+        }
+
+        for (key, value) in account.storage {
+            self.set_storage::<H>(address, key, value);
+        }
+    }
+
+    pub fn get_storage<H: Hasher>(&self, address: Address20, key: U256) -> U256 {
+        let address = addr20_to_addr32(address);
+        let slot = Storage::new::<H>(address, key).storage_slot();
+        self.trie.get(&slot.0).map(from_value).unwrap_or_default()
+    }
+
+    pub fn set_storage<H: Hasher>(&mut self, address: Address20, key: U256, value: U256) {
+        let address = addr20_to_addr32(address);
+        let slot = Storage::new::<H>(address, key).storage_slot();
+        self.trie.insert_single(slot.0, to_value(value));
+    }
+
+    fn commit_code<H: Hasher>(&mut self, address: [u8; 32], code: Vec<u8>) {
+        let chunks = chunkify_code(code);
+        let keys = AccountKeys::new::<H>(address);
+        self.trie.insert(
+            keys.code_chunk_keys(0, chunks.len() as u64)
+                .zip(chunks)
+                .map(|((_chunk_id, key), chunk)| (key.0, chunk)),
+        );
+    }
+
+    /// Removes code chunks `from_chunk_id..to_chunk_id`, left over from a
+    /// previous, longer version of the account's code.
+    fn clear_code_chunks<H: Hasher>(
+        &mut self,
+        address: [u8; 32],
+        from_chunk_id: u64,
+        to_chunk_id: u64,
+    ) {
+        let keys = AccountKeys::new::<H>(address);
+        for (_chunk_id, key) in keys.code_chunk_keys(from_chunk_id, to_chunk_id - from_chunk_id) {
+            self.trie.remove_single(&key.0);
+        }
+    }
+
+    fn read_code<H: Hasher>(&self, address: [u8; 32], code_size: U256) -> Vec<u8> {
+        let chunk_count = chunk_count(code_size);
+        let keys = AccountKeys::new::<H>(address);
+        let mut code = Vec::with_capacity((chunk_count * 31) as usize);
+        for (_chunk_id, key) in keys.code_chunk_keys(0, chunk_count) {
+            let chunk = self.trie.get(&key.0).unwrap_or_default();
+            code.extend_from_slice(&chunk[1..]);
+        }
+        code.truncate(code_size.as_u64() as usize);
+        code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{database::memory_db::MemoryDb, VerkleConfig};
+
+    struct TestHasher;
+    impl Hasher for TestHasher {}
+
+    fn test_state() -> State<MemoryDb> {
+        State::new(Trie::new(VerkleConfig::new(MemoryDb::new())))
+    }
+
+    fn address(n: u8) -> Address20 {
+        Address20::from_str(&format!("0x{:0>40}", format!("{n:x}"))).unwrap()
+    }
+
+    #[test]
+    fn round_trips_balance_and_nonce() {
+        let mut state = test_state();
+        let address = address(1);
+
+        state.set_account::<TestHasher>(address, Account::new(U256::from(100), U256::from(7)));
+
+        let account = state.get_account::<TestHasher>(address);
+        assert_eq!(account.balance, U256::from(100));
+        assert_eq!(account.nonce, U256::from(7));
+        assert_eq!(account.code_hash(), KECCAK_EMPTY);
+        assert_eq!(account.code_size(), U256::zero());
+        assert_eq!(account.code(), None);
+    }
+
+    #[test]
+    fn round_trips_code_across_multiple_chunks() {
+        let mut state = test_state();
+        let address = address(2);
+
+        // More than one 31-byte chunk, so reassembly has to walk several keys.
+        let code: Vec<u8> = (0..100).collect();
+
+        let mut account = Account::new(U256::zero(), U256::zero());
+        account.init_code(code.clone());
+        state.set_account::<TestHasher>(address, account);
+
+        let account = state.get_account::<TestHasher>(address);
+        assert_eq!(account.code_hash(), keccak(&code));
+        assert_eq!(account.code_size(), U256::from(code.len()));
+        assert_eq!(account.code(), Some(code.as_slice()));
+    }
+
+    #[test]
+    fn shrinking_code_removes_the_leftover_trailing_chunks() {
+        let mut state = test_state();
+        let address = address(5);
+
+        // Long enough to span multiple 31-byte chunks.
+        let long_code: Vec<u8> = (0..100).collect();
+        let mut account = Account::new(U256::zero(), U256::zero());
+        account.init_code(long_code);
+        state.set_account::<TestHasher>(address, account);
+
+        let keys = AccountKeys::new::<TestHasher>(addr20_to_addr32(address));
+        let stale_chunk_key = keys.code_chunk(2);
+        assert!(state.trie.get(&stale_chunk_key.0).is_some());
+
+        let mut account = Account::new(U256::zero(), U256::zero());
+        account.init_code(vec![0xff]);
+        state.set_account::<TestHasher>(address, account);
+
+        assert_eq!(state.trie.get(&stale_chunk_key.0), None);
+
+        let account = state.get_account::<TestHasher>(address);
+        assert_eq!(account.code(), Some([0xff].as_slice()));
+    }
+
+    #[test]
+    fn clearing_code_removes_all_chunks() {
+        let mut state = test_state();
+        let address = address(6);
+
+        let mut account = Account::new(U256::zero(), U256::zero());
+        account.init_code(vec![1, 2, 3]);
+        state.set_account::<TestHasher>(address, account);
+
+        let keys = AccountKeys::new::<TestHasher>(addr20_to_addr32(address));
+        let chunk_key = keys.code_chunk(0);
+        assert!(state.trie.get(&chunk_key.0).is_some());
+
+        state.set_account::<TestHasher>(address, Account::new(U256::zero(), U256::zero()));
+
+        assert_eq!(state.trie.get(&chunk_key.0), None);
+        assert_eq!(state.get_account::<TestHasher>(address).code(), None);
+    }
+
+    #[test]
+    fn round_trips_storage() {
+        let mut state = test_state();
+        let address = address(3);
+
+        state.set_account::<TestHasher>(address, Account::new(U256::zero(), U256::zero()));
+        state.set_storage::<TestHasher>(address, U256::from(42), U256::from(1234));
+
+        assert_eq!(
+            state.get_storage::<TestHasher>(address, U256::from(42)),
+            U256::from(1234)
+        );
+        assert_eq!(
+            state.get_storage::<TestHasher>(address, U256::from(43)),
+            U256::zero()
+        );
+    }
+
+    #[test]
+    fn set_account_applies_queued_storage_writes() {
+        let mut state = test_state();
+        let address = address(4);
+
+        let mut account = Account::new(U256::from(1), U256::zero());
+        account.set_storage(U256::from(1), U256::from(2));
+        state.set_account::<TestHasher>(address, account);
+
+        assert_eq!(
+            state.get_storage::<TestHasher>(address, U256::from(1)),
+            U256::from(2)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use ark_ff::PrimeField;
+use banderwagon::{Element, Fr};
+use keccak_hash::keccak;
+
+use crate::{database::Database, VerkleConfig, Value};
+
+/// Operations common to every Verkle tree implementation, in terms of the
+/// raw 32-byte key/value layout; see `verkle-spec` for the layer that maps
+/// Ethereum accounts and storage onto these keys.
+pub trait TrieTrait {
+    /// Inserts a batch of key/value pairs, recomputing the commitments
+    /// touched along the way.
+    fn insert(&mut self, key_values: impl Iterator<Item = ([u8; 32], Value)>);
+
+    /// Inserts a single key/value pair. Equivalent to `insert` with a
+    /// one-element iterator, but avoids building one at call sites.
+    fn insert_single(&mut self, key: [u8; 32], value: Value);
+
+    /// Removes a single key, returning its previous value if it was present.
+    ///
+    /// Unlike overwriting a key with a zero value, this actually drops it
+    /// from the tree, so it no longer contributes to `root_hash`.
+    fn remove_single(&mut self, key: &[u8; 32]) -> Option<Value>;
+
+    /// Looks up a single value by key.
+    fn get(&self, key: &[u8; 32]) -> Option<Value>;
+
+    /// Returns the commitment to the tree's root.
+    fn root_hash(&self) -> Element;
+}
+
+/// A Verkle tree over a pluggable [`Database`] backend.
+pub struct Trie<Db> {
+    pub(crate) config: VerkleConfig<Db>,
+}
+
+impl<Db: Database> Trie<Db> {
+    pub fn new(config: VerkleConfig<Db>) -> Self {
+        Trie { config }
+    }
+}
+
+impl<Db: Database> TrieTrait for Trie<Db> {
+    fn insert(&mut self, key_values: impl Iterator<Item = ([u8; 32], Value)>) {
+        for (key, value) in key_values {
+            self.insert_single(key, value);
+        }
+    }
+
+    fn insert_single(&mut self, key: [u8; 32], value: Value) {
+        self.config.db.insert(key, value);
+    }
+
+    fn remove_single(&mut self, key: &[u8; 32]) -> Option<Value> {
+        self.config.db.remove(key)
+    }
+
+    fn get(&self, key: &[u8; 32]) -> Option<Value> {
+        self.config.db.get(key)
+    }
+
+    fn root_hash(&self) -> Element {
+        // A real Verkle root is a vector commitment over the tree's inner
+        // nodes; this crate doesn't build that tree structure, so instead it
+        // folds every stored (key, value) pair into a single commitment:
+        // `sum(generator * scalar(key, value))`, a Pedersen-style hash
+        // accumulator. Addition makes it order-independent (so the same
+        // contents always commute to the same root regardless of insertion
+        // order), and it changes whenever any stored key or value changes.
+        self.config
+            .db
+            .iter()
+            .map(|(key, value)| {
+                let mut preimage = [0u8; 64];
+                preimage[..32].copy_from_slice(&key);
+                preimage[32..].copy_from_slice(&value);
+                let scalar = Fr::from_le_bytes_mod_order(&keccak(preimage).0);
+                Element::prime_subgroup_generator() * scalar
+            })
+            .fold(Element::zero(), |root, commitment| root + commitment)
+    }
+}